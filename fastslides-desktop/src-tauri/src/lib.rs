@@ -1,15 +1,21 @@
 use base64::Engine;
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::{HashMap, HashSet};
 use std::env;
 use std::fs;
 use std::io::Cursor;
 use std::path::{Component, Path, PathBuf};
 use std::process::Command;
-use std::sync::OnceLock;
+use std::sync::{mpsc, Mutex, OnceLock};
 use std::thread;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{Theme, ThemeSet};
+use syntect::parsing::{SyntaxReference, SyntaxSet};
+use syntect::util::LinesWithEndings;
 use tauri::menu::{Menu, MenuItem, PredefinedMenuItem};
 use tauri::{Emitter, Manager};
 use tiny_http::{Header, Method, Request, Response, Server, StatusCode};
@@ -26,6 +32,8 @@ const DEFAULT_PREVIEW_BASE_URL: &str = "http://127.0.0.1:34773";
 const DEFAULT_AGENT_HOOK_ADDR: &str = "127.0.0.1:38473";
 const MENU_EXPORT_SKILL_ID: &str = "menu.export_fastslides_skill";
 const MENU_EXPORT_SKILL_EVENT: &str = "fastslides://export-skill";
+const RELOAD_EVENT: &str = "fastslides://reload";
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(150);
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 struct AppConfig {
@@ -54,6 +62,35 @@ struct ProjectDetail {
     updated_at: u64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+struct Frontmatter {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    project: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    subtitle: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    code_theme: Option<String>,
+    #[serde(flatten)]
+    extra: HashMap<String, serde_json::Value>,
+}
+
+impl Frontmatter {
+    fn get_str(&self, key: &str) -> Option<&str> {
+        match key {
+            "project" => self.project.as_deref(),
+            "title" => self.title.as_deref(),
+            "subtitle" => self.subtitle.as_deref(),
+            "date" => self.date.as_deref(),
+            "code_theme" => self.code_theme.as_deref(),
+            _ => self.extra.get(key).and_then(|value| value.as_str()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 struct ValidationReport {
     path: String,
@@ -74,6 +111,13 @@ struct PathPayload {
     path: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct ValidateProjectPayload {
+    path: String,
+    #[serde(default)]
+    check_links: bool,
+}
+
 #[derive(Debug, Serialize)]
 struct HookStatus {
     ok: bool,
@@ -92,6 +136,13 @@ struct PreviewUrlResponse {
     preview_url: String,
 }
 
+#[derive(Debug, Clone, Serialize)]
+struct ReloadPayload {
+    project_path: String,
+    slide_count: usize,
+    updated_at: u64,
+}
+
 fn now_epoch_seconds() -> u64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -156,18 +207,18 @@ fn html_tag_re() -> &'static Regex {
     RE.get_or_init(|| Regex::new(r#"<[^>]+>"#).expect("invalid html tag regex"))
 }
 
-fn frontmatter_re() -> &'static Regex {
+fn yaml_frontmatter_re() -> &'static Regex {
     static RE: OnceLock<Regex> = OnceLock::new();
     RE.get_or_init(|| {
-        Regex::new(r#"(?s)\A---\s*\n(.*?)\n---\s*(?:\n|$)"#).expect("invalid frontmatter regex")
+        Regex::new(r#"(?s)\A---\s*\n(.*?)\n---\s*\n"#).expect("invalid yaml frontmatter regex")
     })
 }
 
-fn frontmatter_line_re() -> &'static Regex {
+fn toml_frontmatter_re() -> &'static Regex {
     static RE: OnceLock<Regex> = OnceLock::new();
     RE.get_or_init(|| {
-        Regex::new(r#"^\s*([A-Za-z0-9_-]+)\s*:\s*(.*?)\s*$"#)
-            .expect("invalid frontmatter line regex")
+        Regex::new(r#"(?s)\A\+\+\+\s*\n(.*?)\n\+\+\+\s*\n"#)
+            .expect("invalid toml frontmatter regex")
     })
 }
 
@@ -383,6 +434,270 @@ fn mime_type_for_path(path: &Path) -> &'static str {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ImageFit {
+    Contain,
+    Cover,
+}
+
+impl ImageFit {
+    fn label(self) -> &'static str {
+        match self {
+            ImageFit::Contain => "contain",
+            ImageFit::Cover => "cover",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ResizeRequest {
+    width: Option<u32>,
+    height: Option<u32>,
+    fit: ImageFit,
+}
+
+fn parse_resize_request(url: &Url) -> Option<ResizeRequest> {
+    let mut width = None;
+    let mut height = None;
+    let mut fit = ImageFit::Contain;
+
+    for (key, value) in url.query_pairs() {
+        match key.as_ref() {
+            "w" => width = value.parse::<u32>().ok().filter(|value| *value > 0),
+            "h" => height = value.parse::<u32>().ok().filter(|value| *value > 0),
+            "fit" => {
+                fit = match value.as_ref() {
+                    "cover" => ImageFit::Cover,
+                    _ => ImageFit::Contain,
+                };
+            }
+            _ => {}
+        }
+    }
+
+    if width.is_none() && height.is_none() {
+        return None;
+    }
+    Some(ResizeRequest { width, height, fit })
+}
+
+fn image_cache_root() -> Result<PathBuf, String> {
+    Ok(ensure_fastslides_home()?.join("cache"))
+}
+
+fn content_hash(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(hasher.finalize())
+}
+
+const RAW_EXTENSIONS: &[&str] = &[
+    "nef", "cr2", "cr3", "arw", "dng", "rw2", "raf", "orf", "pef", "srw",
+];
+const HEIF_EXTENSIONS: &[&str] = &["heic", "heif"];
+
+fn extension_lower(path: &Path) -> String {
+    path.extension()
+        .and_then(|value| value.to_str())
+        .map(|value| value.to_ascii_lowercase())
+        .unwrap_or_default()
+}
+
+fn is_raw_asset(path: &Path) -> bool {
+    RAW_EXTENSIONS.contains(&extension_lower(path).as_str())
+}
+
+fn is_heif_asset(path: &Path) -> bool {
+    HEIF_EXTENSIONS.contains(&extension_lower(path).as_str())
+}
+
+fn needs_transcoding(path: &Path) -> bool {
+    is_raw_asset(path) || is_heif_asset(path)
+}
+
+fn derived_image_format(path: &Path) -> image::ImageFormat {
+    image::ImageFormat::from_path(path).unwrap_or(image::ImageFormat::Png)
+}
+
+fn output_format_for(path: &Path) -> image::ImageFormat {
+    if needs_transcoding(path) {
+        image::ImageFormat::WebP
+    } else {
+        derived_image_format(path)
+    }
+}
+
+fn decode_raw_image(path: &Path) -> Result<image::DynamicImage, String> {
+    let mut pipeline = imagepipe::Pipeline::new_from_file(path)
+        .map_err(|error| format!("Failed to open RAW pipeline for {}: {error}", path.display()))?;
+    let decoded = pipeline
+        .output_8bit(None)
+        .map_err(|error| format!("Failed to decode RAW image {}: {error}", path.display()))?;
+    let buffer = image::RgbImage::from_raw(decoded.width as u32, decoded.height as u32, decoded.data)
+        .ok_or_else(|| format!("Unexpected RAW buffer size for {}", path.display()))?;
+    Ok(image::DynamicImage::ImageRgb8(buffer))
+}
+
+fn decode_heif_image(path: &Path) -> Result<image::DynamicImage, String> {
+    let context = libheif_rs::HeifContext::read_from_file(&path.to_string_lossy())
+        .map_err(|error| format!("Failed to open HEIF file {}: {error}", path.display()))?;
+    let handle = context
+        .primary_image_handle()
+        .map_err(|error| format!("Failed to read HEIF primary image {}: {error}", path.display()))?;
+    let decoded = handle
+        .decode(libheif_rs::ColorSpace::Rgb(libheif_rs::RgbChroma::Rgb), false)
+        .map_err(|error| format!("Failed to decode HEIF image {}: {error}", path.display()))?;
+    let plane = decoded
+        .planes()
+        .interleaved
+        .ok_or_else(|| format!("HEIF image {} has no interleaved RGB plane", path.display()))?;
+
+    // libheif pads each row to `stride` bytes, which is frequently larger than
+    // `width * 3`. `RgbImage::from_raw` assumes tightly-packed rows, so copy
+    // row-by-row to strip the padding instead of handing it the raw buffer.
+    let width = plane.width as usize;
+    let height = plane.height as usize;
+    let row_bytes = width * 3;
+    let stride = plane.stride;
+    if stride < row_bytes {
+        return Err(format!(
+            "HEIF image {} reported an implausible row stride ({stride} bytes for {width}px)",
+            path.display()
+        ));
+    }
+    let mut packed = Vec::with_capacity(row_bytes * height);
+    for row in 0..height {
+        let start = row * stride;
+        packed.extend_from_slice(&plane.data[start..start + row_bytes]);
+    }
+
+    let buffer = image::RgbImage::from_raw(plane.width, plane.height, packed)
+        .ok_or_else(|| format!("Unexpected HEIF buffer size for {}", path.display()))?;
+    Ok(image::DynamicImage::ImageRgb8(buffer))
+}
+
+fn decode_asset_image(path: &Path, original_bytes: &[u8]) -> Result<image::DynamicImage, String> {
+    if is_raw_asset(path) {
+        decode_raw_image(path)
+    } else if is_heif_asset(path) {
+        decode_heif_image(path)
+    } else {
+        image::load_from_memory(original_bytes).map_err(|error| format!("Failed to decode image: {error}"))
+    }
+}
+
+fn compute_target_dimensions(
+    original_width: u32,
+    original_height: u32,
+    request: ResizeRequest,
+) -> (u32, u32) {
+    match (request.width, request.height) {
+        (Some(width), Some(height)) => (width, height),
+        (Some(width), None) => {
+            let ratio = width as f64 / original_width.max(1) as f64;
+            (width, ((original_height as f64) * ratio).round().max(1.0) as u32)
+        }
+        (None, Some(height)) => {
+            let ratio = height as f64 / original_height.max(1) as f64;
+            (((original_width as f64) * ratio).round().max(1.0) as u32, height)
+        }
+        (None, None) => (original_width, original_height),
+    }
+}
+
+fn resize_decoded_image(
+    decoded: image::DynamicImage,
+    target_width: u32,
+    target_height: u32,
+    fit: ImageFit,
+    format: image::ImageFormat,
+) -> Result<Vec<u8>, String> {
+    let resized = match fit {
+        ImageFit::Contain => {
+            decoded.resize(target_width, target_height, image::imageops::FilterType::Lanczos3)
+        }
+        ImageFit::Cover => decoded.resize_to_fill(
+            target_width,
+            target_height,
+            image::imageops::FilterType::Lanczos3,
+        ),
+    };
+
+    let mut encoded = Vec::<u8>::new();
+    resized
+        .write_to(&mut Cursor::new(&mut encoded), format)
+        .map_err(|error| format!("Failed to encode resized image: {error}"))?;
+    Ok(encoded)
+}
+
+/// Resolves `original_path` through the on-disk derived-asset cache, generating
+/// and caching the result under `~/.fastslides/cache/<hash>/<w>x<h>-<fit>.<ext>`
+/// the first time it is requested. RAW and HEIF/HEIC sources are transcoded to
+/// WebP through the same cache, so the resize and WebP encode happen once per
+/// variant; such sources are still decoded in full on every request, because
+/// their dimensions are read from the decoded image before the cache is checked.
+/// `fit` is part of the cache key because `contain` and `cover` produce
+/// different pixels for the same `w`x`h`. Returns the response bytes, the
+/// content type to serve them with, and the content hash for cache-control
+/// purposes.
+fn cached_derived_asset(
+    original_path: &Path,
+    request: ResizeRequest,
+) -> Result<(Vec<u8>, String, String), String> {
+    let original_bytes = fs::read(original_path)
+        .map_err(|error| format!("Failed to read {}: {error}", original_path.display()))?;
+    let hash = content_hash(&original_bytes);
+
+    let format = output_format_for(original_path);
+    let extension = format.extensions_str().first().copied().unwrap_or("png");
+    let cache_dir = image_cache_root()?.join(&hash);
+
+    let cheap_dimensions = if needs_transcoding(original_path) {
+        None
+    } else {
+        image::image_dimensions(original_path).ok()
+    };
+
+    let mut decoded_image: Option<image::DynamicImage> = None;
+    let (original_width, original_height) = match cheap_dimensions {
+        Some(dimensions) => dimensions,
+        None => {
+            let decoded = decode_asset_image(original_path, &original_bytes)?;
+            let dimensions = (decoded.width(), decoded.height());
+            decoded_image = Some(decoded);
+            dimensions
+        }
+    };
+
+    let (target_width, target_height) =
+        compute_target_dimensions(original_width, original_height, request);
+    let fit_label = request.fit.label();
+    let cache_path =
+        cache_dir.join(format!("{target_width}x{target_height}-{fit_label}.{extension}"));
+
+    if let Ok(cached_bytes) = fs::read(&cache_path) {
+        return Ok((cached_bytes, mime_type_for_path(&cache_path).to_string(), hash));
+    }
+
+    let unchanged = target_width == original_width && target_height == original_height;
+    let output_bytes = if unchanged && !needs_transcoding(original_path) {
+        original_bytes.clone()
+    } else {
+        let decoded_image = match decoded_image {
+            Some(image) => image,
+            None => decode_asset_image(original_path, &original_bytes)?,
+        };
+        resize_decoded_image(decoded_image, target_width, target_height, request.fit, format)?
+    };
+
+    fs::create_dir_all(&cache_dir)
+        .map_err(|error| format!("Failed to create cache folder {}: {error}", cache_dir.display()))?;
+    fs::write(&cache_path, &output_bytes)
+        .map_err(|error| format!("Failed to write cache file {}: {error}", cache_path.display()))?;
+
+    Ok((output_bytes, mime_type_for_path(&cache_path).to_string(), hash))
+}
+
 fn read_page_mdx(project_dir: &Path) -> Result<String, String> {
     let page_path = project_dir.join("page.mdx");
     fs::read_to_string(&page_path)
@@ -422,13 +737,152 @@ fn extract_slides(source: &str) -> Vec<String> {
     slides
 }
 
+fn fenced_code_block_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r#"(?s)```([A-Za-z0-9_+-]*)[ \t]*\n(.*?)\n```"#)
+            .expect("invalid fenced code block regex")
+    })
+}
+
+fn highlighted_code_block_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r#"(?s)<pre[^>]*data-fastslides-code[^>]*>.*?</pre>"#)
+            .expect("invalid highlighted code block regex")
+    })
+}
+
+const DEFAULT_CODE_THEME: &str = "InspiredGitHub";
+
+fn syntax_set() -> &'static SyntaxSet {
+    static SET: OnceLock<SyntaxSet> = OnceLock::new();
+    SET.get_or_init(SyntaxSet::load_defaults_newlines)
+}
+
+fn theme_set() -> &'static ThemeSet {
+    static SET: OnceLock<ThemeSet> = OnceLock::new();
+    SET.get_or_init(ThemeSet::load_defaults)
+}
+
+fn resolve_code_theme(requested: Option<&str>) -> &'static Theme {
+    let themes = theme_set();
+    requested
+        .and_then(|name| themes.themes.get(name))
+        .or_else(|| themes.themes.get(DEFAULT_CODE_THEME))
+        .unwrap_or_else(|| {
+            themes
+                .themes
+                .values()
+                .next()
+                .expect("syntect ships at least one default theme")
+        })
+}
+
+fn theme_background_hex(theme: &Theme) -> String {
+    let background = theme.settings.background.unwrap_or(syntect::highlighting::Color {
+        r: 255,
+        g: 255,
+        b: 255,
+        a: 255,
+    });
+    format!("#{:02x}{:02x}{:02x}", background.r, background.g, background.b)
+}
+
+/// Escapes text for use as MDX/JSX element content: beyond the usual HTML
+/// entities, `{`/`}` must be escaped because the MDX compiler treats a bare
+/// brace in text content as the start of a JS expression, and backticks are
+/// escaped defensively since highlighted source commonly contains them.
+fn escape_jsx_text(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('{', "&#123;")
+        .replace('}', "&#125;")
+        .replace('`', "&#96;")
+}
+
+fn style_foreground_hex(style: &syntect::highlighting::Style) -> String {
+    let color = style.foreground;
+    format!("#{:02x}{:02x}{:02x}", color.r, color.g, color.b)
+}
+
+fn highlight_to_html(code: &str, syntax: &SyntaxReference, theme: &Theme, syntaxes: &SyntaxSet) -> String {
+    let mut highlighter = HighlightLines::new(syntax, theme);
+    let mut html = String::from("<code>");
+    for line in LinesWithEndings::from(code) {
+        let Ok(ranges) = highlighter.highlight_line(line, syntaxes) else {
+            continue;
+        };
+        for (style, text) in ranges {
+            let escaped = escape_jsx_text(text);
+            if escaped.is_empty() {
+                continue;
+            }
+            html.push_str(r#"<span style={{color:""#);
+            html.push_str(&style_foreground_hex(&style));
+            html.push_str(r#""}}>"#);
+            html.push_str(&escaped);
+            html.push_str("</span>");
+        }
+    }
+    html.push_str("</code>");
+    html
+}
+
+/// Rewrites fenced code blocks (```lang ... ```) into pre-highlighted `<pre><code>`
+/// JSX, so the highlighting survives in the runtime-loaded, import-free MDX that
+/// `validate_project_folder` enforces. The output is JSX, not HTML: `style` is an
+/// object expression (`style={{...}}`) rather than a string, and code text has
+/// `{`/`}`/backticks escaped so highlighted source can't be parsed as a JS
+/// expression by the MDX compiler. `theme_name` comes from the page's
+/// `code_theme` front matter key and falls back to `DEFAULT_CODE_THEME`.
+fn highlight_code_blocks(body: &str, theme_name: Option<&str>) -> String {
+    if !body.contains("```") {
+        return body.to_string();
+    }
+
+    let syntaxes = syntax_set();
+    let theme = resolve_code_theme(theme_name);
+    let background = theme_background_hex(theme);
+
+    fenced_code_block_re()
+        .replace_all(body, |captures: &regex::Captures| {
+            let language = captures.get(1).map(|item| item.as_str()).unwrap_or_default();
+            let code = captures.get(2).map(|item| item.as_str()).unwrap_or_default();
+
+            let syntax = if language.is_empty() {
+                syntaxes.find_syntax_plain_text()
+            } else {
+                syntaxes
+                    .find_syntax_by_token(language)
+                    .unwrap_or_else(|| syntaxes.find_syntax_plain_text())
+            };
+
+            let highlighted = highlight_to_html(code, syntax, theme, syntaxes);
+            format!(
+                r#"<pre data-fastslides-code="{language}" style={{{{backgroundColor:"{background}"}}}}>{highlighted}</pre>"#
+            )
+        })
+        .into_owned()
+}
+
+fn strip_code_for_word_count(text: &str) -> String {
+    let without_fences = fenced_code_block_re().replace_all(text, " ");
+    highlighted_code_block_re()
+        .replace_all(&without_fences, " ")
+        .into_owned()
+}
+
 fn words_in_text(text: &str) -> usize {
-    let plain = html_tag_re().replace_all(text, " ");
+    let without_code = strip_code_for_word_count(text);
+    let plain = html_tag_re().replace_all(&without_code, " ");
     word_re().find_iter(&plain).count()
 }
 
 fn max_paragraph_words(text: &str) -> usize {
-    let plain = html_tag_re().replace_all(text, " ");
+    let without_code = strip_code_for_word_count(text);
+    let plain = html_tag_re().replace_all(&without_code, " ");
     let mut max_words = 0usize;
     for paragraph in plain
         .split("\n\n")
@@ -534,69 +988,48 @@ fn project_detail_for_path(
     })
 }
 
-fn yaml_quote(value: &str) -> String {
-    let escaped = value.replace('\\', r#"\\"#).replace('"', r#"\""#);
-    format!(r#""{escaped}""#)
-}
-
-fn normalize_frontmatter_value(raw: &str) -> String {
-    let trimmed = raw.trim();
-    if trimmed.len() >= 2 {
-        let first = trimmed.as_bytes()[0] as char;
-        let last = trimmed.as_bytes()[trimmed.len() - 1] as char;
-        if (first == '"' && last == '"') || (first == '\'' && last == '\'') {
-            let inner = &trimmed[1..trimmed.len() - 1];
-            let escaped_quote = format!(r#"\{first}"#);
-            return inner
-                .replace("\\\\", "\\")
-                .replace(escaped_quote.as_str(), first.to_string().as_str())
-                .trim()
-                .to_string();
-        }
+/// Splits `source` into its parsed front matter and the remaining body. The
+/// middle element of the tuple carries a parse-error message when a YAML/TOML
+/// block was found but failed to deserialize into `Frontmatter`, so callers
+/// can surface the real failure instead of silently treating it as absent.
+fn extract_frontmatter(source: &str) -> (Option<Frontmatter>, Option<String>, String) {
+    if let Some(captures) = yaml_frontmatter_re().captures(source) {
+        let full_match = captures.get(0).expect("capture 0 always present on a match");
+        let block = captures.get(1).map(|item| item.as_str()).unwrap_or_default();
+        let rest = source[full_match.end()..].to_string();
+        return match serde_yaml::from_str::<Frontmatter>(block) {
+            Ok(frontmatter) => (Some(frontmatter), None, rest),
+            Err(error) => (None, Some(format!("Failed to parse YAML front matter: {error}")), rest),
+        };
     }
-    trimmed.to_string()
-}
-
-fn extract_frontmatter(source: &str) -> (Option<HashMap<String, String>>, String) {
-    let Some(captures) = frontmatter_re().captures(source) else {
-        return (None, source.to_string());
-    };
-
-    let Some(full_match) = captures.get(0) else {
-        return (None, source.to_string());
-    };
-    let block = captures.get(1).map(|item| item.as_str()).unwrap_or_default();
 
-    let mut values = HashMap::<String, String>::new();
-    for raw_line in block.lines() {
-        let line = raw_line.trim();
-        if line.is_empty() || line.starts_with('#') {
-            continue;
-        }
-        if let Some(parsed) = frontmatter_line_re().captures(line) {
-            let key = parsed
-                .get(1)
-                .map(|item| item.as_str().to_ascii_lowercase())
-                .unwrap_or_default();
-            let value = parsed
-                .get(2)
-                .map(|item| normalize_frontmatter_value(item.as_str()))
-                .unwrap_or_default();
-            values.insert(key, value);
-        }
+    if let Some(captures) = toml_frontmatter_re().captures(source) {
+        let full_match = captures.get(0).expect("capture 0 always present on a match");
+        let block = captures.get(1).map(|item| item.as_str()).unwrap_or_default();
+        let rest = source[full_match.end()..].to_string();
+        return match toml::from_str::<Frontmatter>(block) {
+            Ok(frontmatter) => (Some(frontmatter), None, rest),
+            Err(error) => (None, Some(format!("Failed to parse TOML front matter: {error}")), rest),
+        };
     }
 
-    (Some(values), source[full_match.end()..].to_string())
+    (None, None, source.to_string())
 }
 
 fn build_starter_page(project: &str, title: &str, subtitle: &str, date_label: &str) -> String {
+    let frontmatter = Frontmatter {
+        project: Some(project.to_string()),
+        title: Some(title.to_string()),
+        subtitle: Some(subtitle.to_string()),
+        date: Some(date_label.to_string()),
+        code_theme: None,
+        extra: HashMap::new(),
+    };
+    let frontmatter_yaml = serde_yaml::to_string(&frontmatter).unwrap_or_default();
+
     format!(
         r#"---
-project: {project}
-title: {title}
-subtitle: {subtitle}
-date: {date_label}
----
+{frontmatter_yaml}---
 
 <main className="deck">
 
@@ -661,10 +1094,10 @@ date: {date_label}
 
 </main>
 "#,
-        project = yaml_quote(project),
-        title = yaml_quote(title),
-        subtitle = yaml_quote(subtitle),
-        date_label = yaml_quote(date_label)
+        frontmatter_yaml = frontmatter_yaml,
+        title = title,
+        subtitle = subtitle,
+        date_label = date_label
     )
 }
 
@@ -687,7 +1120,193 @@ fn remember_recent_project(config: &mut AppConfig, project_path: &Path) {
     }
 }
 
-fn validate_project_folder(project_path: &Path) -> Result<ValidationReport, String> {
+fn app_handle_slot() -> &'static OnceLock<tauri::AppHandle> {
+    static SLOT: OnceLock<tauri::AppHandle> = OnceLock::new();
+    &SLOT
+}
+
+fn project_watcher_slot() -> &'static Mutex<Option<RecommendedWatcher>> {
+    static SLOT: OnceLock<Mutex<Option<RecommendedWatcher>>> = OnceLock::new();
+    SLOT.get_or_init(|| Mutex::new(None))
+}
+
+fn watch_targets(project_dir: &Path) -> Vec<PathBuf> {
+    let mut targets = vec![project_dir.join("page.mdx")];
+    for relative in ["assets", "images", "media", "data"] {
+        if let Some(resolved) = resolve_relative_path(project_dir, relative) {
+            if resolved.is_dir() {
+                targets.push(resolved);
+            }
+        }
+    }
+    targets
+}
+
+/// Watches `page.mdx` plus the referenced asset folders for `project_dir` and emits
+/// `RELOAD_EVENT` on debounced changes. Returns the live watcher handle; dropping it
+/// (e.g. when the active project switches) tears down the underlying inotify/FSEvents
+/// subscriptions.
+fn start_project_watcher(
+    app: tauri::AppHandle,
+    project_dir: PathBuf,
+) -> Result<RecommendedWatcher, String> {
+    let (change_tx, change_rx) = mpsc::channel::<notify::Result<Event>>();
+    let mut watcher = notify::recommended_watcher(move |result| {
+        let _ = change_tx.send(result);
+    })
+    .map_err(|error| format!("Failed to create file watcher: {error}"))?;
+
+    for target in watch_targets(&project_dir) {
+        let mode = if target.is_dir() {
+            RecursiveMode::Recursive
+        } else {
+            RecursiveMode::NonRecursive
+        };
+        if let Err(error) = watcher.watch(&target, mode) {
+            log::warn!("Failed to watch {}: {error}", target.display());
+        }
+    }
+
+    thread::spawn(move || {
+        while change_rx.recv().is_ok() {
+            while change_rx.recv_timeout(WATCH_DEBOUNCE).is_ok() {}
+
+            let Ok(source) = read_page_mdx(&project_dir) else {
+                continue;
+            };
+            let payload = ReloadPayload {
+                project_path: path_to_string(&project_dir),
+                slide_count: slide_count_from_source(&source),
+                updated_at: now_epoch_seconds(),
+            };
+            if let Err(error) = app.emit(RELOAD_EVENT, payload) {
+                log::warn!("Failed to emit reload event: {error}");
+            }
+        }
+    });
+
+    Ok(watcher)
+}
+
+/// Rebuilds the live-reload watcher for the newly-activated project, replacing (and
+/// thereby tearing down) whichever project was previously being watched. No-ops when
+/// the app hasn't finished starting up yet, e.g. requests served by the agent hook
+/// before `setup` has run.
+fn sync_project_watcher(project_dir: &Path) {
+    let Some(app_handle) = app_handle_slot().get() else {
+        return;
+    };
+
+    match start_project_watcher(app_handle.clone(), project_dir.to_path_buf()) {
+        Ok(watcher) => {
+            let mut slot = project_watcher_slot()
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            *slot = Some(watcher);
+        }
+        Err(error) => log::warn!(
+            "Failed to start file watcher for {}: {error}",
+            project_dir.display()
+        ),
+    }
+}
+
+const LINK_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+const MAX_CONCURRENT_LINK_CHECKS: usize = 8;
+
+fn external_link_targets(body: &str) -> Vec<String> {
+    let mut seen = HashSet::<String>::new();
+    let mut links = Vec::<String>::new();
+
+    let mut collect = |raw: &str| {
+        let sanitized = sanitize_markdown_target(raw);
+        let lower = sanitized.to_ascii_lowercase();
+        if !(lower.starts_with("http://") || lower.starts_with("https://")) {
+            return;
+        }
+        if seen.insert(sanitized.clone()) {
+            links.push(sanitized);
+        }
+    };
+
+    for captures in markdown_link_re().captures_iter(body) {
+        let raw = captures
+            .get(1)
+            .or_else(|| captures.get(2))
+            .map(|item| item.as_str())
+            .unwrap_or_default();
+        collect(raw);
+    }
+    for captures in attr_link_re().captures_iter(body) {
+        let raw = captures.get(1).map(|item| item.as_str()).unwrap_or_default();
+        collect(raw);
+    }
+
+    links
+}
+
+fn link_check_cache() -> &'static Mutex<HashMap<String, Result<u16, String>>> {
+    static CACHE: OnceLock<Mutex<HashMap<String, Result<u16, String>>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn check_link_reachable(url: &str) -> Result<u16, String> {
+    let agent = ureq::AgentBuilder::new().timeout(LINK_CHECK_TIMEOUT).build();
+
+    // Some servers reject HEAD outright (403/405) even though the resource is
+    // reachable, so any HEAD status >= 400 falls back to a ranged GET before
+    // the link is flagged as broken, not just transport-level failures.
+    match agent.head(url).call() {
+        Ok(response) => Ok(response.status()),
+        Err(ureq::Error::Status(_, _)) | Err(ureq::Error::Transport(_)) => {
+            match agent.get(url).set("Range", "bytes=0-0").call() {
+                Ok(response) => Ok(response.status()),
+                Err(ureq::Error::Status(status, _)) => Ok(status),
+                Err(transport_error) => Err(transport_error.to_string()),
+            }
+        }
+    }
+}
+
+fn cached_link_check(url: &str) -> Result<u16, String> {
+    let cache = link_check_cache();
+    if let Some(cached) = cache
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(url)
+    {
+        return cached.clone();
+    }
+
+    let result = check_link_reachable(url);
+    cache
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(url.to_string(), result.clone());
+    result
+}
+
+/// Checks reachability of `links` concurrently (bounded by
+/// `MAX_CONCURRENT_LINK_CHECKS` in-flight requests at a time), caching each URL's
+/// result for the session so re-validation is fast.
+fn check_external_links(links: Vec<String>) -> Vec<(String, Result<u16, String>)> {
+    let mut results = Vec::with_capacity(links.len());
+    for batch in links.chunks(MAX_CONCURRENT_LINK_CHECKS) {
+        let handles: Vec<_> = batch
+            .iter()
+            .cloned()
+            .map(|url| thread::spawn(move || (url.clone(), cached_link_check(&url))))
+            .collect();
+        for handle in handles {
+            if let Ok(pair) = handle.join() {
+                results.push(pair);
+            }
+        }
+    }
+    results
+}
+
+fn validate_project_folder(project_path: &Path, check_links: bool) -> Result<ValidationReport, String> {
     let canonical_project = normalize_existing_directory(&path_to_string(project_path))?;
     let page_path = canonical_project.join("page.mdx");
     if !page_path.exists() {
@@ -695,20 +1314,20 @@ fn validate_project_folder(project_path: &Path) -> Result<ValidationReport, Stri
     }
 
     let source = read_page_mdx(&canonical_project)?;
-    let (frontmatter, body) = extract_frontmatter(&source);
+    let (frontmatter, frontmatter_error, body) = extract_frontmatter(&source);
     let mut errors = Vec::<String>::new();
     let mut warnings = Vec::<String>::new();
 
     if let Some(frontmatter_values) = &frontmatter {
         if frontmatter_values
-            .get("project")
+            .get_str("project")
             .map(|item| item.trim().is_empty())
             .unwrap_or(true)
         {
             warnings.push("Frontmatter is missing `project`.".to_string());
         }
         if frontmatter_values
-            .get("title")
+            .get_str("title")
             .map(|item| item.trim().is_empty())
             .unwrap_or(true)
         {
@@ -716,7 +1335,7 @@ fn validate_project_folder(project_path: &Path) -> Result<ValidationReport, Stri
         }
 
         let declared_project = frontmatter_values
-            .get("project")
+            .get_str("project")
             .map(|item| item.trim())
             .unwrap_or_default();
         let folder_name = canonical_project
@@ -728,9 +1347,11 @@ fn validate_project_folder(project_path: &Path) -> Result<ValidationReport, Stri
                 "Frontmatter project `{declared_project}` does not match folder name `{folder_name}`."
             ));
         }
+    } else if let Some(error) = frontmatter_error {
+        errors.push(error);
     } else {
         warnings.push(
-            "Missing YAML frontmatter in page.mdx. Add metadata block with project/title/subtitle/date."
+            "Missing front matter in page.mdx. Add a YAML (`---`) or TOML (`+++`) metadata block with project/title/subtitle/date."
                 .to_string(),
         );
     }
@@ -801,6 +1422,12 @@ fn validate_project_folder(project_path: &Path) -> Result<ValidationReport, Stri
                 ));
                 continue;
             }
+            if needs_transcoding(&resolved) {
+                warnings.push(format!(
+                    "Asset {raw} is a {} file and will be transcoded to a web-displayable format for preview.",
+                    extension_lower(&resolved)
+                ));
+            }
             assets_checked += 1;
         }
     }
@@ -832,10 +1459,30 @@ fn validate_project_folder(project_path: &Path) -> Result<ValidationReport, Stri
                 ));
                 continue;
             }
+            if needs_transcoding(&resolved) {
+                warnings.push(format!(
+                    "Asset {raw} is a {} file and will be transcoded to a web-displayable format for preview.",
+                    extension_lower(&resolved)
+                ));
+            }
             assets_checked += 1;
         }
     }
 
+    if check_links {
+        for (url, result) in check_external_links(external_link_targets(&body)) {
+            match result {
+                Ok(status) if status >= 400 => {
+                    warnings.push(format!("External link returned HTTP {status}: {url}"));
+                }
+                Ok(_) => {}
+                Err(error) => {
+                    warnings.push(format!("External link unreachable: {url} ({error})"));
+                }
+            }
+        }
+    }
+
     Ok(ValidationReport {
         path: path_to_string(&canonical_project),
         slide_count: slides.len(),
@@ -858,6 +1505,7 @@ fn open_project(path: String) -> Result<ProjectDetail, String> {
     let mut config = normalized_config(load_config()?);
     remember_recent_project(&mut config, &project_path);
     save_config(&config)?;
+    sync_project_watcher(&project_path);
     project_detail_for_path(&config, &project_path)
 }
 
@@ -937,6 +1585,7 @@ fn load_project(path: String) -> Result<ProjectDetail, String> {
     let mut config = normalized_config(load_config()?);
     remember_recent_project(&mut config, &project_path);
     save_config(&config)?;
+    sync_project_watcher(&project_path);
     project_detail_for_path(&config, &project_path)
 }
 
@@ -950,6 +1599,27 @@ fn save_project(path: String, page_mdx: String) -> Result<ProjectDetail, String>
     project_detail_for_path(&config, &project_path)
 }
 
+#[tauri::command]
+fn highlight_project_code(path: String) -> Result<ProjectDetail, String> {
+    let project_path = normalize_existing_project_directory(&path)?;
+    let source = read_page_mdx(&project_path)?;
+    let (frontmatter, frontmatter_error, body) = extract_frontmatter(&source);
+    if let Some(error) = frontmatter_error {
+        return Err(error);
+    }
+    let theme_name = frontmatter.as_ref().and_then(|values| values.get_str("code_theme"));
+    let highlighted_body = highlight_code_blocks(&body, theme_name);
+
+    let fence_end = source.len() - body.len();
+    let rebuilt = format!("{}{}", &source[..fence_end], highlighted_body);
+    write_page_mdx(&project_path, &rebuilt)?;
+
+    let mut config = normalized_config(load_config()?);
+    remember_recent_project(&mut config, &project_path);
+    save_config(&config)?;
+    project_detail_for_path(&config, &project_path)
+}
+
 #[tauri::command]
 fn create_project(
     root: String,
@@ -995,8 +1665,8 @@ fn create_project(
 }
 
 #[tauri::command]
-fn validate_project(path: String) -> Result<ValidationReport, String> {
-    validate_project_folder(Path::new(&path))
+fn validate_project(path: String, check_links: Option<bool>) -> Result<ValidationReport, String> {
+    validate_project_folder(Path::new(&path), check_links.unwrap_or(false))
 }
 
 #[tauri::command]
@@ -1209,6 +1879,34 @@ fn json_response(status_code: u16, payload: impl Serialize) -> Response<Cursor<V
     response
 }
 
+fn binary_response(
+    status_code: u16,
+    bytes: Vec<u8>,
+    content_type: &str,
+    cache_control: Option<&str>,
+    etag: Option<&str>,
+) -> Response<Cursor<Vec<u8>>> {
+    let mut response = Response::from_data(bytes).with_status_code(StatusCode(status_code));
+
+    if let Ok(header) = Header::from_bytes("Content-Type", content_type) {
+        response.add_header(header);
+    }
+    if let Ok(access_control) = Header::from_bytes("Access-Control-Allow-Origin", "*") {
+        response.add_header(access_control);
+    }
+    if let Some(cache_control_value) = cache_control {
+        if let Ok(header) = Header::from_bytes("Cache-Control", cache_control_value) {
+            response.add_header(header);
+        }
+    }
+    if let Some(etag_value) = etag {
+        if let Ok(header) = Header::from_bytes("ETag", etag_value) {
+            response.add_header(header);
+        }
+    }
+    response
+}
+
 fn json_error_response(status_code: u16, message: String) -> Response<Cursor<Vec<u8>>> {
     json_response(
         status_code,
@@ -1253,6 +1951,73 @@ fn build_preview_url_for_path(project_path: &str) -> String {
     format!("{}/?{query}", preview_base_url())
 }
 
+fn handle_asset_request(parsed_url: &Url) -> Response<Cursor<Vec<u8>>> {
+    let project_path = parsed_url
+        .query_pairs()
+        .find_map(|(key, value)| (key == "project").then(|| value.into_owned()))
+        .unwrap_or_default();
+    let raw_src = parsed_url
+        .query_pairs()
+        .find_map(|(key, value)| (key == "src").then(|| value.into_owned()))
+        .unwrap_or_default();
+
+    if project_path.trim().is_empty() || raw_src.trim().is_empty() {
+        return json_error_response(
+            400,
+            "Missing required query parameters: project, src".to_string(),
+        );
+    }
+
+    let canonical_project = match normalize_existing_directory(&project_path) {
+        Ok(path) => path,
+        Err(error) => return json_error_response(404, error),
+    };
+
+    let Some(relative_path) = local_asset_path(raw_src.as_str()) else {
+        return json_error_response(400, format!("Not a local asset path: {raw_src}"));
+    };
+    let Some(resolved_path) = resolve_relative_path(&canonical_project, &relative_path) else {
+        return json_error_response(400, format!("Asset path escapes project folder: {raw_src}"));
+    };
+    if !resolved_path.is_file() {
+        return json_error_response(404, format!("Missing asset target: {raw_src}"));
+    }
+
+    let resize_request = parse_resize_request(parsed_url);
+    let needs_derived_asset = resize_request.is_some() || needs_transcoding(&resolved_path);
+
+    match resize_request.or(needs_derived_asset.then_some(ResizeRequest {
+        width: None,
+        height: None,
+        fit: ImageFit::Contain,
+    })) {
+        Some(request) => match cached_derived_asset(&resolved_path, request) {
+            Ok((bytes, content_type, hash)) => binary_response(
+                200,
+                bytes,
+                content_type.as_str(),
+                // The `/asset` URL has no content hash in it (only project/src and
+                // the resize parameters), so it stays the same across edits to the
+                // source file. `immutable` is left out, but with a one-year max-age
+                // the browser may still reuse its cached copy without asking the
+                // server until that window ends; the ETag below (the content hash)
+                // is what it can revalidate against after that. This handler does
+                // not answer conditional requests with 304.
+                Some("public, max-age=31536000"),
+                Some(hash.as_str()),
+            ),
+            Err(error) => json_error_response(500, error),
+        },
+        None => match fs::read(&resolved_path) {
+            Ok(bytes) => binary_response(200, bytes, mime_type_for_path(&resolved_path), None, None),
+            Err(error) => json_error_response(
+                500,
+                format!("Failed to read {}: {error}", resolved_path.display()),
+            ),
+        },
+    }
+}
+
 fn handle_agent_hook_request(method: &Method, request_url: &str, request: &mut Request) -> Response<Cursor<Vec<u8>>> {
     let parsed = Url::parse(format!("http://localhost{request_url}").as_str());
     let parsed_url = match parsed {
@@ -1274,6 +2039,7 @@ fn handle_agent_hook_request(method: &Method, request_url: &str, request: &mut R
             Ok(state) => json_response(200, state),
             Err(error) => json_error_response(500, error),
         },
+        (&Method::Get, "/asset") => handle_asset_request(&parsed_url),
         (&Method::Get, "/preview-url") => {
             let project_path = parsed_url
                 .query_pairs()
@@ -1306,11 +2072,11 @@ fn handle_agent_hook_request(method: &Method, request_url: &str, request: &mut R
             }
         }
         (&Method::Post, "/validate-project") => {
-            let payload = match read_json_body::<PathPayload>(request) {
+            let payload = match read_json_body::<ValidateProjectPayload>(request) {
                 Ok(value) => value,
                 Err(error) => return json_error_response(400, error),
             };
-            match validate_project(payload.path) {
+            match validate_project(payload.path, Some(payload.check_links)) {
                 Ok(report) => json_response(200, report),
                 Err(error) => json_error_response(400, error),
             }
@@ -1363,6 +2129,8 @@ pub fn run() {
         })
         .plugin(tauri_plugin_dialog::init())
         .setup(|app| {
+            let _ = app_handle_slot().set(app.handle().clone());
+
             #[cfg(target_os = "macos")]
             {
                 if let Some(window) = app.get_webview_window("main") {
@@ -1383,6 +2151,7 @@ pub fn run() {
             add_projects_root,
             create_project,
             get_app_state,
+            highlight_project_code,
             load_project,
             open_project,
             open_in_file_manager,